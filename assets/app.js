@@ -0,0 +1,412 @@
+// State
+let currentPath = "";
+let parentPath = null;
+let changeSocket = null;
+let changeRefresh = null;
+
+// Platform detection
+const isWindows = navigator.platform.includes("Win");
+
+// Helper functions
+function getHome() {
+    if (isWindows) {
+        return `C:\\Users\\${USERNAME || 'Public'}`;
+    }
+    return "/home";
+}
+
+function getDesktop() {
+    if (isWindows) {
+        return `C:\\Users\\${USERNAME || 'Public'}\\Desktop`;
+    }
+    return "/home/Desktop";
+}
+
+function getDocuments() {
+    if (isWindows) {
+        return `C:\\Users\\${USERNAME || 'Public'}\\Documents`;
+    }
+    return "/home/Documents";
+}
+
+function getDownloads() {
+    if (isWindows) {
+        return `C:\\Users\\${USERNAME || 'Public'}\\Downloads`;
+    }
+    return "/home/Downloads";
+}
+
+function getPictures() {
+    if (isWindows) {
+        return `C:\\Users\\${USERNAME || 'Public'}\\Pictures`;
+    }
+    return "/home/Pictures";
+}
+
+function setPath(path) {
+    document.getElementById("pathInput").value = path;
+    loadDirectory();
+}
+
+// The server explains failures as { error, code }; fall back to the status if it didn't
+async function errorMessage(response, action) {
+    try {
+        const body = await response.json();
+        if (body && body.error) {
+            return `${action}: ${body.error}`;
+        }
+    } catch (e) {
+        // Not JSON, use the status below
+    }
+    return `${action} (Status: ${response.status})`;
+}
+
+function showToast(message, duration = 3000) {
+    const toast = document.getElementById("toast");
+    toast.textContent = message;
+    toast.classList.add("show");
+    setTimeout(() => {
+        toast.classList.remove("show");
+    }, duration);
+}
+
+function escapeHtml(text) {
+    const div = document.createElement('div');
+    div.textContent = text;
+    return div.innerHTML;
+}
+
+function formatFileSize(bytes) {
+    if (bytes === 0) return '0 Bytes';
+    const k = 1024;
+    const sizes = ['Bytes', 'KB', 'MB', 'GB'];
+    const i = Math.floor(Math.log(bytes) / Math.log(k));
+    return parseFloat((bytes / Math.pow(k, i)).toFixed(1)) + ' ' + sizes[i];
+}
+
+// Main directory loading function
+async function loadDirectory(path = null) {
+    const input = document.getElementById("pathInput");
+    const targetPath = path || input.value.trim();
+    
+    if (!targetPath) {
+        showToast("Please enter a directory path");
+        return;
+    }
+    
+    const grid = document.getElementById("grid");
+    const statsBar = document.getElementById("statsBar");
+    
+    // Show loading state
+    grid.innerHTML = `
+        <div class="empty-state">
+            <div class="loading-spinner"></div>
+            <div class="empty-state-title" style="margin-top: 20px;">Loading directory...</div>
+        </div>
+    `;
+    
+    statsBar.style.display = "none";
+    
+    try {
+        // Fetch directory listing
+        const response = await fetch(`/api/list?path=${encodeURIComponent(targetPath)}`);
+        
+        if (!response.ok) {
+            throw new Error(await errorMessage(response, "Failed to load directory"));
+        }
+        
+        const data = await response.json();
+        
+        // Update state
+        currentPath = data.current_path;
+        parentPath = data.parent_path;
+        input.value = currentPath;
+        watchCurrentPath();
+        
+        // Update breadcrumb
+        document.getElementById("breadcrumbPath").textContent = currentPath;
+        
+        // Clear grid
+        grid.innerHTML = "";
+        
+        // Check if directory is empty
+        if (data.entries.length === 0) {
+            grid.innerHTML = `
+                <div class="empty-state">
+                    <div class="empty-state-icon">📂</div>
+                    <div class="empty-state-title">Empty Directory</div>
+                    <div class="empty-state-text">This folder doesn't contain any images or folders</div>
+                </div>
+            `;
+            return;
+        }
+        
+        // Count statistics
+        const folders = data.entries.filter(e => e.is_dir);
+        const images = data.entries.filter(e => e.is_image);
+        
+        // Create grid items
+        data.entries.forEach(entry => {
+            const item = document.createElement("div");
+            item.className = "grid-item";
+            
+            if (entry.is_dir) {
+                // Folder card
+                item.innerHTML = `
+                    <div class="card folder-card">
+                        <div class="folder-icon">📁</div>
+                        <div class="folder-name">${escapeHtml(entry.name)}</div>
+                    </div>
+                `;
+                item.querySelector(".folder-card").addEventListener("click", () => loadDirectory(entry.path));
+            } else if (entry.is_image) {
+                // Image card
+                const safeName = escapeHtml(entry.name);
+                
+                item.innerHTML = `
+                    <div class="card">
+                        <div class="image-container">
+                            <img src="/image/${encodeURIComponent(entry.path)}" 
+                                 alt="${safeName}" 
+                                 class="image-preview" 
+                                 loading="lazy">
+                            
+                            <div class="image-overlay">
+                                <div class="image-name" title="${safeName}">${safeName}</div>
+                                <div class="image-actions">
+                                    <button class="action-btn rename-btn">
+                                        ✏️ Rename
+                                    </button>
+                                    <button class="action-btn delete-btn">
+                                        🗑️ Delete
+                                    </button>
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+                `;
+                item.querySelector(".image-preview").addEventListener("click", () => viewImage(entry.path));
+                item.querySelector(".rename-btn").addEventListener("click", (event) => {
+                    event.stopPropagation();
+                    renameFile(entry.path, entry.name);
+                });
+                item.querySelector(".delete-btn").addEventListener("click", (event) => {
+                    event.stopPropagation();
+                    deleteFile(entry.path);
+                });
+            }
+            
+            grid.appendChild(item);
+        });
+        
+        // Update stats
+        document.getElementById("folderCount").textContent = folders.length;
+        document.getElementById("imageCount").textContent = images.length;
+        document.getElementById("totalCount").textContent = data.entries.length;
+        statsBar.style.display = "flex";
+        
+        showToast(`Loaded ${images.length} images and ${folders.length} folders`);
+        
+    } catch (error) {
+        console.error("Error loading directory:", error);
+        
+        grid.innerHTML = `
+            <div class="empty-state">
+                <div class="empty-state-icon">⚠️</div>
+                <div class="empty-state-title">Error Loading Directory</div>
+                <div class="empty-state-text">${escapeHtml(error.message)}</div>
+            </div>
+        `;
+        
+        showToast(`Error: ${error.message}`, 5000);
+    }
+}
+
+function goUp() {
+    if (parentPath) {
+        loadDirectory(parentPath);
+    } else {
+        showToast("Already at the root directory");
+    }
+}
+
+function viewImage(imagePath) {
+    const modal = document.getElementById("imageModal");
+    const modalImg = document.getElementById("modalImage");
+    
+    modalImg.src = `/image/${encodeURIComponent(imagePath)}`;
+    modal.classList.add("active");
+    document.body.style.overflow = "hidden";
+}
+
+function closeModal() {
+    const modal = document.getElementById("imageModal");
+    const modalImg = document.getElementById("modalImage");
+    
+    modal.classList.remove("active");
+    modalImg.src = "";
+    document.body.style.overflow = "";
+}
+
+async function uploadFiles(input) {
+    const files = Array.from(input.files);
+    input.value = "";
+    
+    if (!currentPath || files.length === 0) {
+        return;
+    }
+    
+    const form = new FormData();
+    files.forEach(file => form.append("file", file));
+    
+    try {
+        const response = await fetch(`/api/upload?path=${encodeURIComponent(currentPath)}`, {
+            method: "POST",
+            body: form
+        });
+        
+        if (!response.ok) {
+            throw new Error(await errorMessage(response, "Upload failed"));
+        }
+        
+        const saved = await response.json();
+        showToast(`Uploaded ${saved.length} image${saved.length === 1 ? "" : "s"}`);
+        
+        // Refresh current directory
+        setTimeout(() => loadDirectory(currentPath), 500);
+        
+    } catch (error) {
+        console.error("Upload error:", error);
+        showToast(`Error: ${error.message}`, 5000);
+    }
+}
+
+async function deleteFile(filePath) {
+    const fileName = filePath.split(/[\\/]/).pop();
+    
+    if (!confirm(`Delete "${fileName}"?\n\nIt will be moved to the .trash folder.`)) {
+        return;
+    }
+    
+    try {
+        const response = await fetch(`/api/delete?path=${encodeURIComponent(filePath)}`, {
+            method: "POST"
+        });
+        
+        if (!response.ok) {
+            throw new Error(await errorMessage(response, "Delete failed"));
+        }
+        
+        showToast(`Moved "${fileName}" to the trash`);
+        
+        // Refresh current directory
+        setTimeout(() => loadDirectory(currentPath), 500);
+        
+    } catch (error) {
+        console.error("Delete error:", error);
+        showToast(`Error: ${error.message}`, 5000);
+    }
+}
+
+async function renameFile(oldPath, oldName) {
+    const newName = prompt(`Rename "${oldName}" to:`, oldName);
+    
+    if (!newName || newName.trim() === "" || newName === oldName) {
+        return;
+    }
+    
+    try {
+        const response = await fetch("/api/rename", {
+            method: "POST",
+            headers: {
+                "Content-Type": "application/json"
+            },
+            body: JSON.stringify({
+                old_path: oldPath,
+                new_name: newName.trim()
+            })
+        });
+        
+        if (!response.ok) {
+            if (response.status === 409) {
+                throw new Error("A file with that name already exists");
+            }
+            throw new Error(await errorMessage(response, "Rename failed"));
+        }
+        
+        showToast(`Renamed to "${newName}" (backup created)`);
+        
+        // Refresh current directory
+        setTimeout(() => loadDirectory(currentPath), 500);
+        
+    } catch (error) {
+        console.error("Rename error:", error);
+        showToast(`Error: ${error.message}`, 5000);
+    }
+}
+
+// Live updates: the server pushes changes in whichever folder we last told it about
+function watchCurrentPath() {
+    if (changeSocket && changeSocket.readyState === WebSocket.OPEN) {
+        changeSocket.send(currentPath);
+        return;
+    }
+    if (changeSocket) {
+        return;
+    }
+    
+    const scheme = location.protocol === "https:" ? "wss" : "ws";
+    changeSocket = new WebSocket(`${scheme}://${location.host}/ws`);
+    changeSocket.addEventListener("open", () => changeSocket.send(currentPath));
+    changeSocket.addEventListener("message", (event) => {
+        const change = JSON.parse(event.data);
+        if (!change.kind) {
+            return;
+        }
+        // A burst of changes, like a copy of many files, becomes one reload
+        clearTimeout(changeRefresh);
+        changeRefresh = setTimeout(() => loadDirectory(currentPath), 300);
+    });
+    changeSocket.addEventListener("close", () => {
+        changeSocket = null;
+        setTimeout(() => currentPath && watchCurrentPath(), 5000);
+    });
+}
+
+// Event Listeners, attached here since the page's CSP allows no inline handlers
+document.getElementById("goButton").addEventListener("click", () => loadDirectory());
+document.getElementById("loadButton").addEventListener("click", () => loadDirectory());
+document.getElementById("upButton").addEventListener("click", goUp);
+document.getElementById("uploadButton").addEventListener("click", () => document.getElementById("uploadInput").click());
+document.getElementById("uploadInput").addEventListener("change", (event) => uploadFiles(event.target));
+document.getElementById("modalClose").addEventListener("click", closeModal);
+
+const quickPlaces = {
+    users: () => "C:\\Users",
+    drive: () => "C:\\",
+    home: getHome,
+    desktop: getDesktop,
+    documents: getDocuments,
+    pictures: getPictures,
+    downloads: getDownloads,
+};
+document.querySelectorAll(".quick-btn").forEach((button) => {
+    button.addEventListener("click", () => setPath(quickPlaces[button.dataset.place]()));
+});
+
+document.getElementById("pathInput").addEventListener("keypress", (event) => {
+    if (event.key === "Enter") {
+        loadDirectory();
+    }
+});
+
+document.addEventListener("keydown", (event) => {
+    if (event.key === "Escape") {
+        closeModal();
+    }
+});
+
+// Initialize with home directory
+window.addEventListener("DOMContentLoaded", () => {
+    setPath(getHome());
+});